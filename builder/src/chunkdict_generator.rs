@@ -13,6 +13,10 @@
 //! Description: The current logic for calculating the chunk number is based on the formula size/chunk size.
 //! However, this approach is flawed as it precedes the actual check which accounts for chunk statistics.
 //! Consequently, this leads to inaccurate counting of chunk numbers.
+//! -------------------------------------------------------------------------------------------------
+//! This module additionally depends on `crc32fast` for frame/blob checksums and on `serde` /
+//! `serde_json` for chunkdict and prefetch-blob metadata (de)serialization; the builder crate's
+//! `Cargo.toml` must list them as dependencies.
 
 use super::core::node::{ChunkSource, NodeInfo};
 use super::{BlobManager, Bootstrap, BootstrapManager, BuildContext, BuildOutput, Tree};
@@ -22,7 +26,7 @@ use crate::OsString;
 use crate::Path;
 use crate::TreeNode;
 use crate::{ArtifactWriter, BlobContext, NodeChunk};
-use anyhow::{Ok, Result};
+use anyhow::{bail, Context, Ok, Result};
 use nydus_rafs::metadata::chunk::ChunkWrapper;
 use nydus_rafs::metadata::inode::InodeWrapper;
 use nydus_rafs::metadata::layout::v6::RafsV6BlobTable;
@@ -33,6 +37,7 @@ use nydus_storage::meta::BlobChunkInfoV1Ondisk;
 use nydus_utils::compress;
 use nydus_utils::compress::Algorithm;
 use nydus_utils::digest::RafsDigest;
+use serde::{Deserialize, Serialize};
 use sha2::digest::Update;
 
 use crate::finalize_blob;
@@ -47,7 +52,7 @@ use std::str::FromStr;
 use std::sync::Arc;
 use std::u32;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ChunkdictChunkInfo {
     pub image_reference: String,
     pub version: String,
@@ -59,6 +64,7 @@ pub struct ChunkdictChunkInfo {
     pub chunk_uncompressed_offset: u64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChunkdictBlobInfo {
     pub blob_id: String,
     pub blob_compressed_size: u64,
@@ -69,43 +75,484 @@ pub struct ChunkdictBlobInfo {
     pub blob_meta_ci_offset: u64,
 }
 
-// TODO(daiyongxuan): implement Read Trait for BlobNodeReader
+/// On-disk representation of a chunkdict's inputs, dumped and restored as JSON so a build can
+/// be inspected, edited, or replayed without re-reading the source blobs it was built from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ChunkdictDump {
+    chunks: Vec<ChunkdictChunkInfo>,
+    blobs: Vec<ChunkdictBlobInfo>,
+}
+
+/// Min/avg/stddev/max distribution of `chunk_uncompressed_size` across a chunkdict.
+#[derive(Debug, Clone, Default)]
+pub struct ChunkSizeStats {
+    pub min: u32,
+    pub max: u32,
+    pub avg: f64,
+    pub stddev: f64,
+}
+
+impl ChunkSizeStats {
+    fn new(sizes: &[u32]) -> Self {
+        if sizes.is_empty() {
+            return ChunkSizeStats::default();
+        }
+        let min = *sizes.iter().min().unwrap();
+        let max = *sizes.iter().max().unwrap();
+        let avg = sizes.iter().map(|&s| s as f64).sum::<f64>() / sizes.len() as f64;
+        let variance = sizes
+            .iter()
+            .map(|&s| (s as f64 - avg).powi(2))
+            .sum::<f64>()
+            / sizes.len() as f64;
+        ChunkSizeStats {
+            min,
+            max,
+            avg,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+/// Deduplication statistics for a single blob (or, as `"*"`, across all blobs).
+#[derive(Debug, Clone)]
+pub struct BlobDedupStats {
+    pub blob_id: String,
+    pub total_chunks: u64,
+    pub unique_chunks: u64,
+    pub duplicate_chunks: u64,
+    pub total_uncompressed_size: u64,
+    pub total_compressed_size: u64,
+    pub dedup_uncompressed_size: u64,
+    pub dedup_compressed_size: u64,
+}
+
+impl BlobDedupStats {
+    fn new(blob_id: String) -> Self {
+        BlobDedupStats {
+            blob_id,
+            total_chunks: 0,
+            unique_chunks: 0,
+            duplicate_chunks: 0,
+            total_uncompressed_size: 0,
+            total_compressed_size: 0,
+            dedup_uncompressed_size: 0,
+            dedup_compressed_size: 0,
+        }
+    }
+
+    fn add_chunk(&mut self, chunk: &ChunkdictChunkInfo, is_first_occurrence: bool) {
+        self.total_chunks += 1;
+        self.total_uncompressed_size += chunk.chunk_uncompressed_size as u64;
+        self.total_compressed_size += chunk.chunk_compressed_size as u64;
+        if is_first_occurrence {
+            self.unique_chunks += 1;
+            self.dedup_uncompressed_size += chunk.chunk_uncompressed_size as u64;
+            self.dedup_compressed_size += chunk.chunk_compressed_size as u64;
+        } else {
+            self.duplicate_chunks += 1;
+        }
+    }
+
+    /// Percentage of uncompressed bytes saved by deduplication.
+    pub fn saved_percent(&self) -> f64 {
+        if self.total_uncompressed_size == 0 {
+            return 0.0;
+        }
+        let saved = self.total_uncompressed_size - self.dedup_uncompressed_size;
+        saved as f64 / self.total_uncompressed_size as f64 * 100.0
+    }
+}
+
+/// Structured result of a chunkdict dedup pass, returned from [`Generator::generate`] so
+/// callers can print or serialize it instead of relying on `debug!` output.
+#[derive(Debug, Clone)]
+pub struct DedupStats {
+    pub overall: BlobDedupStats,
+    pub per_blob: Vec<BlobDedupStats>,
+    pub chunk_size_stats: ChunkSizeStats,
+}
+
+/// One independently-decompressible frame written into a blob, mapping its uncompressed
+/// offset to where its compressed bytes live.
+///
+/// A chunk whose uncompressed content is entirely zero is recorded as a "fill" frame: `is_hole`
+/// is set, `compressed_size` is zero, and no payload is written to the blob at all. Readers
+/// reconstruct the zeros from `uncompressed_size` instead of reading `compressed_offset`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BlobFrameDescriptor {
+    pub uncompressed_offset: u64,
+    pub uncompressed_size: u32,
+    pub compressed_offset: u64,
+    pub compressed_size: u32,
+    pub is_hole: bool,
+    /// CRC32 of the chunk's compressed bytes (zero for holes), for cheap corruption checks
+    /// that don't require a full cryptographic digest pass.
+    pub crc32: u32,
+}
+
+/// Seekable index of the independent compression frames written into a blob, sorted by
+/// `uncompressed_offset`, so an individual chunk can be located and decompressed without
+/// touching the rest of the blob.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BlobFrameIndex {
+    frames: Vec<BlobFrameDescriptor>,
+}
+
+impl BlobFrameIndex {
+    pub fn push(&mut self, frame: BlobFrameDescriptor) {
+        self.frames.push(frame);
+    }
+
+    /// Binary-search for the index of the frame covering `uncompressed_offset`.
+    fn locate_index(&self, uncompressed_offset: u64) -> Option<usize> {
+        match self
+            .frames
+            .binary_search_by(|frame| frame.uncompressed_offset.cmp(&uncompressed_offset))
+        {
+            Ok(idx) => Some(idx),
+            Err(0) => None,
+            Err(idx) => Some(idx - 1),
+        }
+    }
+
+    /// Binary-search for the frame covering `uncompressed_offset`.
+    fn locate(&self, uncompressed_offset: u64) -> Option<&BlobFrameDescriptor> {
+        self.locate_index(uncompressed_offset)
+            .and_then(|idx| self.frames.get(idx))
+    }
+
+    /// End of frame `idx`'s logical slot: its real data plus the 4K-alignment padding before
+    /// the next frame starts (or, for the last frame, just its real data with no padding).
+    fn slot_end(&self, idx: usize) -> u64 {
+        match self.frames.get(idx + 1) {
+            Some(next) => next.uncompressed_offset,
+            None => {
+                let frame = &self.frames[idx];
+                frame.uncompressed_offset + frame.uncompressed_size as u64
+            }
+        }
+    }
+
+    /// Total uncompressed length covered by the index.
+    fn uncompressed_len(&self) -> u64 {
+        self.frames
+            .last()
+            .map(|f| f.uncompressed_offset + f.uncompressed_size as u64)
+            .unwrap_or(0)
+    }
+}
+
+/// A `Read + Seek` reader over a blob that transparently decompresses on the fly, using a
+/// [`BlobFrameIndex`] to decompress only the frame covering the requested data instead of
+/// linearly inflating the whole blob.
 #[derive(Debug)]
 #[allow(dead_code)]
 pub struct BlobNodeReader {
     blob: Arc<File>,
     start: u64,
-    end: u64,
     position: u64,
+    compressor: compress::Algorithm,
+    frame_index: BlobFrameIndex,
+    // Total length of the data this reader exposes, in the same (uncompressed) space as
+    // `position`: `frame_index.uncompressed_len()` when frames are present, since `end` is a
+    // compressed-space bound that nothing in uncompressed space can be validly compared
+    // against; otherwise `end - start`, since the passthrough fallback never decompresses and
+    // so has no separate uncompressed space to track.
+    uncompressed_len: u64,
+    // Decompressed bytes of the frame currently being served, and `position`'s offset into it.
+    frame_buf: Vec<u8>,
+    frame_uncompressed_offset: u64,
 }
 
 impl BlobNodeReader {
     pub fn new(blob: Arc<File>, start: u64, end: u64) -> Result<Self> {
-        let mut reader = BlobNodeReader {
+        Self::with_frame_index(
             blob,
             start,
             end,
+            compress::Algorithm::None,
+            BlobFrameIndex::default(),
+        )
+    }
+
+    /// Build a reader that decompresses frames described by `frame_index`, whose
+    /// `uncompressed_offset`s are relative to `start`.
+    pub fn with_frame_index(
+        blob: Arc<File>,
+        start: u64,
+        end: u64,
+        compressor: compress::Algorithm,
+        frame_index: BlobFrameIndex,
+    ) -> Result<Self> {
+        let uncompressed_len = if frame_index.frames.is_empty() {
+            end.saturating_sub(start)
+        } else {
+            frame_index.uncompressed_len()
+        };
+        let mut reader = BlobNodeReader {
+            blob,
+            start,
             position: start,
+            compressor,
+            frame_index,
+            uncompressed_len,
+            frame_buf: Vec::new(),
+            frame_uncompressed_offset: u64::MAX,
         };
         reader.blob.seek(std::io::SeekFrom::Start(start))?;
         Ok(reader)
     }
+
+    /// Decompress (or load raw, if `compressor` is `None`) the frame covering
+    /// `uncompressed_offset` into `self.frame_buf`, unless it is already loaded.
+    fn load_frame(&mut self, uncompressed_offset: u64) -> std::io::Result<()> {
+        let frame = *self.frame_index.locate(uncompressed_offset).ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "no frame covers the requested offset",
+            )
+        })?;
+        if self.frame_uncompressed_offset == frame.uncompressed_offset && !self.frame_buf.is_empty()
+        {
+            return Ok(());
+        }
+
+        if frame.is_hole {
+            self.frame_buf = vec![0u8; frame.uncompressed_size as usize];
+            self.frame_uncompressed_offset = frame.uncompressed_offset;
+            return Ok(());
+        }
+
+        let mut compressed = vec![0u8; frame.compressed_size as usize];
+        self.blob
+            .seek(std::io::SeekFrom::Start(self.start + frame.compressed_offset))?;
+        self.blob.read_exact(&mut compressed)?;
+
+        if crc32fast::hash(&compressed) != frame.crc32 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "chunk CRC32 mismatch",
+            ));
+        }
+
+        self.frame_buf = if self.compressor == compress::Algorithm::None {
+            compressed
+        } else {
+            let mut uncompressed = vec![0u8; frame.uncompressed_size as usize];
+            compress::decompress(&compressed, &mut uncompressed, self.compressor).map_err(
+                |e| std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string()),
+            )?;
+            uncompressed
+        };
+        self.frame_uncompressed_offset = frame.uncompressed_offset;
+        Ok(())
+    }
 }
 
 impl Read for BlobNodeReader {
     fn read(&mut self, buf: &mut [u8]) -> Result<usize, std::io::Error> {
-        // EOF
-        if self.position > self.end {
+        let relative_pos = self.position - self.start;
+        // EOF: compare against the reader's own (uncompressed) length, not `end`, which is a
+        // compressed-space file bound and not comparable to a position tracked in
+        // uncompressed space once frames are being decompressed.
+        if relative_pos >= self.uncompressed_len {
             return std::io::Result::Ok(0);
         }
-        let max_read = (self.end - self.position) as usize;
-        let to_read = std::cmp::min(buf.len(), max_read);
-        let bytes_read = self.blob.read(&mut buf[..to_read])?;
-        self.position += bytes_read as u64;
-        std::io::Result::Ok(bytes_read)
+        if self.frame_index.frames.is_empty() {
+            // No frame index: fall back to the original raw, uncompressed passthrough.
+            let max_read = (self.uncompressed_len - relative_pos) as usize;
+            let to_read = std::cmp::min(buf.len(), max_read);
+            let bytes_read = self.blob.read(&mut buf[..to_read])?;
+            self.position += bytes_read as u64;
+            return std::io::Result::Ok(bytes_read);
+        }
+
+        let idx = match self.frame_index.locate_index(relative_pos) {
+            Some(idx) => idx,
+            None => return std::io::Result::Ok(0),
+        };
+        self.load_frame(relative_pos)?;
+        let frame_offset = (relative_pos - self.frame_uncompressed_offset) as usize;
+        if frame_offset < self.frame_buf.len() {
+            let to_read = std::cmp::min(buf.len(), self.frame_buf.len() - frame_offset);
+            buf[..to_read].copy_from_slice(&self.frame_buf[frame_offset..frame_offset + to_read]);
+            self.position += to_read as u64;
+            return std::io::Result::Ok(to_read);
+        }
+
+        // `relative_pos` landed past this frame's real data but still inside its slot: it is
+        // in the 4K-alignment padding before the next frame, which reads back as zeros rather
+        // than EOF.
+        let slot_end = self.frame_index.slot_end(idx);
+        if relative_pos >= slot_end {
+            return std::io::Result::Ok(0);
+        }
+        let to_read = std::cmp::min(buf.len(), (slot_end - relative_pos) as usize);
+        for b in buf[..to_read].iter_mut() {
+            *b = 0;
+        }
+        self.position += to_read as u64;
+        std::io::Result::Ok(to_read)
+    }
+}
+
+impl Seek for BlobNodeReader {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        let relative_len = self.uncompressed_len;
+        let new_relative = match pos {
+            std::io::SeekFrom::Start(offset) => offset as i64,
+            std::io::SeekFrom::End(offset) => relative_len as i64 + offset,
+            std::io::SeekFrom::Current(offset) => (self.position - self.start) as i64 + offset,
+        };
+        if new_relative < 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "seek to a negative position",
+            ));
+        }
+        self.position = self.start + new_relative as u64;
+        if self.frame_index.frames.is_empty() {
+            // The passthrough read path reads from the file cursor, so move it too.
+            self.blob.seek(std::io::SeekFrom::Start(self.position))?;
+        }
+        Ok(new_relative as u64)
+    }
+}
+
+/// Number of entries in the FastCDC Gear hash table, one per possible byte value.
+const GEAR_TABLE_SIZE: usize = 256;
+
+/// Build the Gear hash table used by [`FastCdc`] to roll a fingerprint over input bytes.
+///
+/// The table is filled with fixed-seed pseudo-random values via splitmix64 so that chunk
+/// boundaries are reproducible across runs without pulling in an external RNG dependency.
+fn gear_table() -> [u64; GEAR_TABLE_SIZE] {
+    let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut table = [0u64; GEAR_TABLE_SIZE];
+    for entry in table.iter_mut() {
+        seed = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = seed;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        *entry = z ^ (z >> 31);
+    }
+    table
+}
+
+/// Parameters controlling FastCDC's normalized chunking behavior.
+#[derive(Debug, Clone, Copy)]
+pub struct FastCdcConfig {
+    pub min_size: usize,
+    pub avg_size: usize,
+    pub max_size: usize,
+    mask_s: u64,
+    mask_l: u64,
+}
+
+impl FastCdcConfig {
+    /// Derive a config from the target average chunk size: `min`/`max` are a quarter and four
+    /// times `avg_size`, and the normalized-chunking masks are sized off the number of bits
+    /// needed to address `avg_size`.
+    pub fn new(avg_size: usize) -> Self {
+        let bits = (avg_size.max(2) as f64).log2().round() as u32;
+        FastCdcConfig {
+            min_size: (avg_size / 4).max(1),
+            avg_size,
+            max_size: avg_size * 4,
+            mask_s: Self::mask_with_bits(bits + 1),
+            mask_l: Self::mask_with_bits(bits.saturating_sub(1)),
+        }
+    }
+
+    /// A mask whose low `bits` bits are set to one.
+    fn mask_with_bits(bits: u32) -> u64 {
+        if bits == 0 {
+            0
+        } else {
+            u64::MAX >> (64 - bits.min(63))
+        }
     }
 }
 
+/// Content-defined chunker implementing FastCDC.
+///
+/// Rolls a Gear-hash fingerprint over the input and declares a chunk boundary once the
+/// fingerprint matches a size-dependent mask, so that inserting or deleting bytes only
+/// perturbs the chunks adjacent to the edit instead of every following fixed-size chunk.
+pub struct FastCdc<'a> {
+    data: &'a [u8],
+    pos: usize,
+    config: FastCdcConfig,
+    gear: [u64; GEAR_TABLE_SIZE],
+}
+
+impl<'a> FastCdc<'a> {
+    pub fn new(data: &'a [u8], config: FastCdcConfig) -> Self {
+        FastCdc {
+            data,
+            pos: 0,
+            config,
+            gear: gear_table(),
+        }
+    }
+}
+
+impl<'a> Iterator for FastCdc<'a> {
+    type Item = (u64, u64);
+
+    /// Return the `(offset, length)` of the next chunk, or `None` once the input is exhausted.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.data.len() {
+            return None;
+        }
+        let start = self.pos;
+        let remaining = self.data.len() - start;
+        if remaining <= self.config.min_size {
+            self.pos = self.data.len();
+            return Some((start as u64, remaining as u64));
+        }
+
+        let max_len = remaining.min(self.config.max_size);
+        let mut fp: u64 = 0;
+        let mut len = self.config.min_size;
+        while len < max_len {
+            let byte = self.data[start + len];
+            fp = (fp << 1).wrapping_add(self.gear[byte as usize]);
+            let mask = if len < self.config.avg_size {
+                self.config.mask_s
+            } else {
+                self.config.mask_l
+            };
+            len += 1;
+            if fp & mask == 0 {
+                break;
+            }
+        }
+        self.pos = start + len;
+        Some((start as u64, len as u64))
+    }
+}
+
+/// How source blob data is segmented before being folded into the chunkdict.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkingMode {
+    /// Reuse the fixed-size boundaries the blob was originally built with.
+    Fixed,
+    /// Re-segment the blob with FastCDC so dedup survives insertions/deletions.
+    FastCdc,
+}
+
+/// Sidecar metadata dumped alongside the prefetch blob, so a downstream reader can validate it
+/// as a whole (`merkle_root`, `blob_crc32`) and correctly interpret holes (`frame_index`)
+/// without redoing the bookkeeping `generate_prefetch` did in memory while writing it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct PrefetchBlobMetadata {
+    merkle_root: String,
+    blob_crc32: u32,
+    frame_index: BlobFrameIndex,
+}
+
 /// Struct to generate chunkdict RAFS bootstrap.
 pub struct Generator {}
 
@@ -120,6 +567,15 @@ struct PrefetchBlobState {
     blob_ctx: BlobContext,
     blob_writer: Box<dyn Artifact>,
     chunk_count: u32,
+    // Maps each written chunk's uncompressed offset to where its independent compression
+    // frame landed, so a `BlobNodeReader` can later seek into this blob chunk-by-chunk.
+    frame_index: BlobFrameIndex,
+    // Digest of every chunk copied into the blob so far, in write order, used to compute a
+    // whole-blob Merkle root once the blob is complete.
+    chunk_digests: Vec<RafsDigest>,
+    // Running CRC32 over every non-hole chunk payload written to the blob, for a cheap
+    // whole-blob corruption check alongside the per-chunk CRC32s in the frame index.
+    blob_crc: crc32fast::Hasher,
 }
 
 impl PrefetchBlobState {
@@ -150,8 +606,54 @@ impl PrefetchBlobState {
             blob_ctx,
             blob_writer,
             chunk_count: 0,
+            frame_index: BlobFrameIndex::default(),
+            chunk_digests: Vec::new(),
+            blob_crc: crc32fast::Hasher::new(),
         })
     }
+
+    /// Final CRC32 over every non-hole chunk payload written to the blob.
+    fn blob_crc32(&self) -> u32 {
+        self.blob_crc.clone().finalize()
+    }
+
+    /// Open a random-access reader over the prefetch blob written so far, for verification or
+    /// partial extraction of a single chunk without decompressing the whole blob.
+    #[allow(dead_code)]
+    fn open_reader(&self, blobs_dir_path: &Path) -> Result<BlobNodeReader> {
+        let file = Arc::new(File::open(blobs_dir_path.join("Prefetch-blob"))?);
+        BlobNodeReader::with_frame_index(
+            file,
+            0,
+            self.blob_ctx.current_compressed_offset,
+            self.blob_ctx.blob_compressor,
+            self.frame_index.clone(),
+        )
+    }
+
+    /// Open a random-access reader over a finalized prefetch blob using the frame index
+    /// persisted by [`Generator::save_blob_metadata`], rather than an in-memory
+    /// `PrefetchBlobState` that only exists for the process that wrote the blob.
+    #[allow(dead_code)]
+    fn open_finalized_reader(
+        blobs_dir_path: &Path,
+        blob_id: &str,
+        compressor: compress::Algorithm,
+    ) -> Result<BlobNodeReader> {
+        let metadata_path = blobs_dir_path.join(format!("{}.merkle", blob_id));
+        let metadata_file = File::open(&metadata_path)
+            .with_context(|| format!("failed to open blob metadata file {:?}", metadata_path))?;
+        let metadata: PrefetchBlobMetadata = serde_json::from_reader(metadata_file)
+            .context("failed to parse blob metadata")?;
+
+        let blob_path = blobs_dir_path.join(blob_id);
+        let file = Arc::new(
+            File::open(&blob_path)
+                .with_context(|| format!("failed to open blob file {:?}", blob_path))?,
+        );
+        let end = file.metadata()?.len();
+        BlobNodeReader::with_frame_index(file, 0, end, compressor, metadata.frame_index)
+    }
 }
 
 impl Generator {
@@ -162,10 +664,19 @@ impl Generator {
         blob_mgr: &mut BlobManager,
         chunkdict_chunks_origin: Vec<ChunkdictChunkInfo>,
         chunkdict_blobs: Vec<ChunkdictBlobInfo>,
-    ) -> Result<BuildOutput> {
-        // Validate and remove chunks whose belonged blob sizes are smaller than a block.
+        chunking_mode: ChunkingMode,
+        blobs_dir_path: Option<&Path>,
+    ) -> Result<(BuildOutput, DedupStats)> {
         let mut chunkdict_chunks = chunkdict_chunks_origin.to_vec();
-        Self::validate_and_remove_chunks(ctx, &mut chunkdict_chunks);
+        if chunking_mode == ChunkingMode::FastCdc {
+            // Re-segment each source blob on FastCDC boundaries before folding it into the
+            // dict, so dedup survives insertions/deletions instead of being pinned to the
+            // blob's original fixed-size cut points.
+            chunkdict_chunks =
+                Self::resegment_with_fastcdc(ctx, &chunkdict_chunks, &chunkdict_blobs, blobs_dir_path)?;
+        }
+        // Validate and remove chunks whose belonged blob sizes are smaller than a block.
+        let dedup_stats = Self::validate_and_remove_chunks(ctx, &mut chunkdict_chunks);
         // Build root tree.
         let mut tree = Self::build_root_tree(ctx)?;
 
@@ -185,10 +696,59 @@ impl Generator {
         let storage = &mut bootstrap_mgr.bootstrap_storage;
         bootstrap.dump(ctx, storage, &mut bootstrap_ctx, &blob_table)?;
 
-        BuildOutput::new(blob_mgr, &bootstrap_mgr.bootstrap_storage)
+        let output = BuildOutput::new(blob_mgr, &bootstrap_mgr.bootstrap_storage)?;
+        Ok((output, dedup_stats))
+    }
+
+    /// Dump the inputs to a chunkdict build to a JSON file, mirroring the `thin_dump`/
+    /// `thin_restore` split used for on-disk metadata: the dump is a human-readable, diffable
+    /// artifact that can be inspected or hand-edited before being fed back through
+    /// [`Generator::restore`].
+    pub fn dump(
+        chunks: &[ChunkdictChunkInfo],
+        blobs: &[ChunkdictBlobInfo],
+        path: &Path,
+    ) -> Result<()> {
+        let dump = ChunkdictDump {
+            chunks: chunks.to_vec(),
+            blobs: blobs.to_vec(),
+        };
+        let file = File::create(path)
+            .with_context(|| format!("failed to create chunkdict dump file {:?}", path))?;
+        serde_json::to_writer_pretty(file, &dump).context("failed to serialize chunkdict dump")?;
+        Ok(())
+    }
+
+    /// Parse a chunkdict dump produced by [`Generator::dump`] back into its chunk and blob
+    /// vectors and drive [`Generator::generate`] with them.
+    pub fn restore(
+        path: &Path,
+        ctx: &mut BuildContext,
+        bootstrap_mgr: &mut BootstrapManager,
+        blob_mgr: &mut BlobManager,
+        chunking_mode: ChunkingMode,
+        blobs_dir_path: Option<&Path>,
+    ) -> Result<(BuildOutput, DedupStats)> {
+        let file = File::open(path)
+            .with_context(|| format!("failed to open chunkdict dump file {:?}", path))?;
+        let dump: ChunkdictDump =
+            serde_json::from_reader(file).context("failed to parse chunkdict dump")?;
+        Self::generate(
+            ctx,
+            bootstrap_mgr,
+            blob_mgr,
+            dump.chunks,
+            dump.blobs,
+            chunking_mode,
+            blobs_dir_path,
+        )
     }
 
     /// Generate a new bootstrap for prefetch.
+    ///
+    /// Returns the Merkle root computed over the ordered digests of every chunk copied into
+    /// the prefetch blob, so callers can validate the blob as a whole rather than chunk by
+    /// chunk.
     pub fn generate_prefetch(
         tree: &mut Tree,
         ctx: &mut BuildContext,
@@ -196,7 +756,7 @@ impl Generator {
         blobtable: &mut RafsV6BlobTable,
         blobs_dir_path: PathBuf,
         prefetch_nodes: Vec<TreeNode>,
-    ) -> Result<()> {
+    ) -> Result<RafsDigest> {
         // create a new blob for prefetch layer
         let blob_layer_num = blobtable.entries.len();
 
@@ -211,8 +771,9 @@ impl Generator {
                 &mut batch,
                 blobtable,
                 &blobs_dir_path,
-            );
+            )?;
         }
+        let merkle_root = Self::merkle_root(&blob_state.chunk_digests, ctx.digester);
 
         {
             let prefetch_blob_ctx = &blob_state.blob_ctx;
@@ -229,11 +790,60 @@ impl Generator {
         Self::finalize_blob(ctx, blobtable, &mut blob_state);
 
         debug!("prefetch blob id: {}", ctx.blob_id);
+        debug!("prefetch blob merkle root: {}", merkle_root);
+        debug!("prefetch blob crc32: {:#x}", blob_state.blob_crc32());
+
+        Self::save_blob_metadata(&blobs_dir_path, &ctx.blob_id, &merkle_root, &blob_state)?;
 
         Self::build_and_dump_bootstrap(tree, ctx, bootstrap_mgr, blobtable)?;
+        Ok(merkle_root)
+    }
+
+    /// Persist the prefetch blob's Merkle root, whole-blob CRC32, and frame index next to it
+    /// as `<blob_id>.merkle`, so a reader opening the blob later (not just this in-process
+    /// `PrefetchBlobState`) can validate it and correctly tell hole frames from real data
+    /// instead of just trusting a zeroed `compressed_size`.
+    fn save_blob_metadata(
+        blobs_dir_path: &Path,
+        blob_id: &str,
+        merkle_root: &RafsDigest,
+        blob_state: &PrefetchBlobState,
+    ) -> Result<()> {
+        let metadata = PrefetchBlobMetadata {
+            merkle_root: merkle_root.to_string(),
+            blob_crc32: blob_state.blob_crc32(),
+            frame_index: blob_state.frame_index.clone(),
+        };
+        let path = blobs_dir_path.join(format!("{}.merkle", blob_id));
+        let file = File::create(&path)
+            .with_context(|| format!("failed to create blob metadata file {:?}", path))?;
+        serde_json::to_writer(file, &metadata).context("failed to serialize blob metadata")?;
         Ok(())
     }
 
+    /// Compute a Merkle root over an ordered list of chunk digests, following the blob-level
+    /// integrity model used by content-addressed blob stores: pair up digests and hash the
+    /// concatenation of each pair, level by level, until a single root digest remains.
+    fn merkle_root(digests: &[RafsDigest], algo: nydus_utils::digest::Algorithm) -> RafsDigest {
+        if digests.is_empty() {
+            return RafsDigest::from_buf(&[], algo);
+        }
+        let mut level: Vec<RafsDigest> = digests.to_vec();
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|pair| {
+                    let mut buf = pair[0].data.to_vec();
+                    if let Some(second) = pair.get(1) {
+                        buf.extend_from_slice(&second.data);
+                    }
+                    RafsDigest::from_buf(&buf, algo)
+                })
+                .collect();
+        }
+        level[0]
+    }
+
     fn build_and_dump_bootstrap(
         tree: &mut Tree,
         ctx: &mut BuildContext,
@@ -319,19 +929,19 @@ impl Generator {
         batch: &mut BatchContextGenerator,
         blobtable: &RafsV6BlobTable,
         blobs_dir_path: &Path,
-    ) {
+    ) -> Result<()> {
         let tree_node = tree
             .get_node_mut(&node.borrow().path())
             .unwrap()
             .node
             .as_ref();
-        let blob_id = {
+        let (blob_id, src_compressor, src_digester) = {
             let child = tree_node.borrow();
             child
                 .chunks
                 .first()
                 .and_then(|chunk| blobtable.entries.get(chunk.inner.blob_index() as usize))
-                .map(|entry| entry.blob_id())
+                .map(|entry| (entry.blob_id(), entry.compressor(), entry.digester()))
                 .unwrap()
         };
         let blob_file = Arc::new(File::open(blobs_dir_path.join(blob_id)).unwrap());
@@ -345,6 +955,7 @@ impl Generator {
             let chunks: &mut Vec<NodeChunk> = child.chunks.as_mut();
             let blob_ctx = &mut prefetch_state.blob_ctx;
             let blob_info = &mut prefetch_state.blob_info;
+            let frame_index = &mut prefetch_state.frame_index;
             let encrypted = blob_ctx.blob_compressor != compress::Algorithm::None;
 
             for chunk in chunks {
@@ -356,8 +967,36 @@ impl Generator {
                 )
                 .unwrap();
                 let buf = &mut vec![0u8; inner.compressed_size() as usize];
-                reader.read_exact(buf).unwrap();
-                prefetch_state.blob_writer.write_all(buf).unwrap();
+                reader
+                    .read_exact(buf)
+                    .with_context(|| format!("failed to read chunk from blob {}", blob_id))?;
+
+                // Verify the bytes we are about to splice into the prefetch blob actually
+                // match the digest recorded for this chunk, instead of trusting the copy.
+                let decompressed = if src_compressor == compress::Algorithm::None {
+                    buf.clone()
+                } else {
+                    let mut out = vec![0u8; inner.uncompressed_size() as usize];
+                    compress::decompress(buf, &mut out, src_compressor)
+                        .context("failed to decompress prefetch chunk for verification")?;
+                    out
+                };
+                let actual_digest = RafsDigest::from_buf(&decompressed, src_digester);
+                if actual_digest != inner.id() {
+                    bail!(
+                        "chunk digest mismatch while assembling prefetch blob: expected {}, got {}",
+                        inner.id(),
+                        actual_digest
+                    );
+                }
+                prefetch_state.chunk_digests.push(actual_digest);
+
+                // A chunk whose uncompressed content is entirely zero is a hole: record it as
+                // a fill descriptor and skip writing (and compressing) any payload for it.
+                let is_hole = decompressed.iter().all(|&b| b == 0);
+                let stored_compressed_size = if is_hole { 0 } else { inner.compressed_size() };
+                let chunk_crc32 = if is_hole { 0 } else { crc32fast::hash(buf) };
+
                 let info = batch
                     .generate_chunk_info(
                         blob_ctx.current_compressed_offset,
@@ -366,6 +1005,16 @@ impl Generator {
                         encrypted,
                     )
                     .unwrap();
+                // Each chunk is an independent compression frame in the written blob, so
+                // record its offsets before they are advanced below.
+                frame_index.push(BlobFrameDescriptor {
+                    uncompressed_offset: blob_ctx.current_uncompressed_offset,
+                    uncompressed_size: inner.uncompressed_size(),
+                    compressed_offset: blob_ctx.current_compressed_offset,
+                    compressed_size: stored_compressed_size,
+                    is_hole,
+                    crc32: chunk_crc32,
+                });
                 inner.set_blob_index(blob_info.blob_index());
                 inner.set_index(prefetch_state.chunk_count);
                 prefetch_state.chunk_count += 1;
@@ -373,12 +1022,19 @@ impl Generator {
                 inner.set_uncompressed_offset(blob_ctx.current_uncompressed_offset);
                 let aligned_d_size: u64 =
                     nydus_utils::try_round_up_4k(inner.uncompressed_size()).unwrap();
-                blob_ctx.compressed_blob_size += inner.compressed_size() as u64;
+
+                if is_hole {
+                    inner.set_compressed_size(0);
+                } else {
+                    prefetch_state.blob_writer.write_all(buf).unwrap();
+                    prefetch_state.blob_crc.update(buf);
+                    blob_ctx.blob_hash.update(&buf);
+                }
+                blob_ctx.compressed_blob_size += stored_compressed_size as u64;
                 blob_ctx.uncompressed_blob_size += aligned_d_size;
-                blob_ctx.current_compressed_offset += inner.compressed_size() as u64;
+                blob_ctx.current_compressed_offset += stored_compressed_size as u64;
                 blob_ctx.current_uncompressed_offset += aligned_d_size;
                 blob_ctx.add_chunk_meta_info(&inner, Some(info)).unwrap();
-                blob_ctx.blob_hash.update(&buf);
 
                 blob_info.set_meta_ci_compressed_size(
                     (blob_info.meta_ci_compressed_size()
@@ -391,6 +1047,7 @@ impl Generator {
                 );
             }
         }
+        Ok(())
     }
 
     /// Validate tree.
@@ -410,7 +1067,13 @@ impl Generator {
     }
 
     /// Validates and removes chunks with a total uncompressed size smaller than the block size limit.
-    fn validate_and_remove_chunks(ctx: &mut BuildContext, chunkdict: &mut Vec<ChunkdictChunkInfo>) {
+    ///
+    /// Also returns dedup statistics, computed in a second pass over the chunks that remain
+    /// once the small blobs have been removed.
+    fn validate_and_remove_chunks(
+        ctx: &mut BuildContext,
+        chunkdict: &mut Vec<ChunkdictChunkInfo>,
+    ) -> DedupStats {
         let mut chunk_sizes = std::collections::HashMap::new();
 
         // Accumulate the uncompressed size for each chunk_blob_id.
@@ -434,6 +1097,46 @@ impl Generator {
 
         // Retain only chunks with chunk_blob_id that has a total uncompressed size > v6_block_size.
         chunkdict.retain(|chunk| !small_chunks.contains(&chunk.chunk_blob_id));
+
+        // Compute stats after the retain above, so they describe the chunkdict that is
+        // actually generated rather than the pre-filtered one.
+        Self::compute_dedup_stats(chunkdict)
+    }
+
+    /// Compute per-blob and overall deduplication statistics over `chunkdict`.
+    ///
+    /// A chunk's "first occurrence" is tracked both per-blob (for `per_blob` stats) and
+    /// globally across the whole dict (for `overall`), since the same digest can be unique
+    /// within its own blob while being a cross-blob duplicate, or vice versa.
+    fn compute_dedup_stats(chunkdict: &[ChunkdictChunkInfo]) -> DedupStats {
+        let mut per_blob: std::collections::HashMap<String, BlobDedupStats> =
+            std::collections::HashMap::new();
+        let mut seen_per_blob: std::collections::HashMap<String, std::collections::HashSet<String>> =
+            std::collections::HashMap::new();
+        let mut seen_globally = std::collections::HashSet::new();
+        let mut overall = BlobDedupStats::new(String::from("*"));
+        let mut uncompressed_sizes = Vec::with_capacity(chunkdict.len());
+
+        for chunk in chunkdict {
+            let blob_stats = per_blob
+                .entry(chunk.chunk_blob_id.clone())
+                .or_insert_with(|| BlobDedupStats::new(chunk.chunk_blob_id.clone()));
+            let seen_in_blob = seen_per_blob
+                .entry(chunk.chunk_blob_id.clone())
+                .or_default();
+            blob_stats.add_chunk(chunk, seen_in_blob.insert(chunk.chunk_digest.clone()));
+            overall.add_chunk(chunk, seen_globally.insert(chunk.chunk_digest.clone()));
+            uncompressed_sizes.push(chunk.chunk_uncompressed_size);
+        }
+
+        let mut per_blob: Vec<BlobDedupStats> = per_blob.into_values().collect();
+        per_blob.sort_by(|a, b| a.blob_id.cmp(&b.blob_id));
+
+        DedupStats {
+            overall,
+            per_blob,
+            chunk_size_stats: ChunkSizeStats::new(&uncompressed_sizes),
+        }
     }
 
     /// Build the root tree.
@@ -516,6 +1219,127 @@ impl Generator {
         Ok(child)
     }
 
+    /// Re-segment a blob's **uncompressed** content on FastCDC content-defined boundaries and
+    /// build the resulting chunk descriptors, ready to be folded into a chunkdict alongside
+    /// (or instead of) chunks taken from the blob's original, fixed-size cut points.
+    ///
+    /// `data` must already be the blob's reconstructed uncompressed bytes: content-defined
+    /// boundaries only track stable content across insertions/deletions when computed over the
+    /// actual data, not over its compressed representation, where a single-byte edit upstream
+    /// perturbs nearly every compressed byte downstream.
+    pub fn chunkdict_from_fastcdc(
+        data: &[u8],
+        blob_id: &str,
+        image_reference: &str,
+        version: &str,
+        avg_chunk_size: usize,
+        digest_algo: nydus_utils::digest::Algorithm,
+    ) -> Vec<ChunkdictChunkInfo> {
+        let config = FastCdcConfig::new(avg_chunk_size);
+        FastCdc::new(data, config)
+            .map(|(offset, len)| {
+                let slice = &data[offset as usize..(offset + len) as usize];
+                let digest = RafsDigest::from_buf(slice, digest_algo);
+                ChunkdictChunkInfo {
+                    image_reference: image_reference.to_string(),
+                    version: version.to_string(),
+                    chunk_blob_id: blob_id.to_string(),
+                    chunk_digest: digest.to_string(),
+                    chunk_compressed_size: len as u32,
+                    chunk_uncompressed_size: len as u32,
+                    chunk_compressed_offset: offset,
+                    chunk_uncompressed_offset: offset,
+                }
+            })
+            .collect()
+    }
+
+    /// Group `chunkdict_chunks` by `chunk_blob_id`, reconstruct each distinct blob's
+    /// uncompressed content from `blobs_dir_path` by decompressing its existing chunks in
+    /// order, and re-segment that uncompressed content with [`Self::chunkdict_from_fastcdc`],
+    /// discarding the original fixed-size chunk boundaries for that blob.
+    fn resegment_with_fastcdc(
+        ctx: &BuildContext,
+        chunkdict_chunks: &[ChunkdictChunkInfo],
+        chunkdict_blobs: &[ChunkdictBlobInfo],
+        blobs_dir_path: Option<&Path>,
+    ) -> Result<Vec<ChunkdictChunkInfo>> {
+        let blobs_dir_path = blobs_dir_path
+            .context("FastCDC chunking mode requires a blobs directory to re-read source blobs")?;
+
+        let mut blob_attrs: std::collections::HashMap<String, (String, String)> =
+            std::collections::HashMap::new();
+        let mut chunks_by_blob: std::collections::HashMap<String, Vec<&ChunkdictChunkInfo>> =
+            std::collections::HashMap::new();
+        for chunk in chunkdict_chunks {
+            blob_attrs
+                .entry(chunk.chunk_blob_id.clone())
+                .or_insert_with(|| (chunk.image_reference.clone(), chunk.version.clone()));
+            chunks_by_blob
+                .entry(chunk.chunk_blob_id.clone())
+                .or_default()
+                .push(chunk);
+        }
+
+        let mut blob_ids: Vec<&String> = blob_attrs.keys().collect();
+        blob_ids.sort();
+
+        let mut resegmented = Vec::new();
+        for blob_id in blob_ids {
+            let (image_reference, version) = &blob_attrs[blob_id];
+            let compressor = chunkdict_blobs
+                .iter()
+                .find(|blob| &blob.blob_id == blob_id)
+                .map(|blob| Algorithm::from_str(blob.blob_compressor.as_str()))
+                .transpose()?
+                .unwrap_or(Algorithm::None);
+
+            let raw = std::fs::read(blobs_dir_path.join(blob_id)).with_context(|| {
+                format!("failed to read blob {} for FastCDC re-segmentation", blob_id)
+            })?;
+
+            // Reconstruct the blob's uncompressed content by decompressing each of its
+            // existing chunks in uncompressed-offset order, exactly as `process_prefetch_node`
+            // does when splicing chunks into the prefetch blob.
+            let mut chunks = chunks_by_blob.get(blob_id).cloned().unwrap_or_default();
+            chunks.sort_by_key(|chunk| chunk.chunk_uncompressed_offset);
+            let mut data = Vec::new();
+            for chunk in chunks {
+                let start = chunk.chunk_compressed_offset as usize;
+                let end = start + chunk.chunk_compressed_size as usize;
+                let compressed = raw.get(start..end).with_context(|| {
+                    format!(
+                        "chunk compressed range out of bounds in blob {}",
+                        blob_id
+                    )
+                })?;
+                if compressor == Algorithm::None {
+                    data.extend_from_slice(compressed);
+                } else {
+                    let mut uncompressed = vec![0u8; chunk.chunk_uncompressed_size as usize];
+                    compress::decompress(compressed, &mut uncompressed, compressor)
+                        .with_context(|| {
+                            format!(
+                                "failed to decompress chunk of blob {} for FastCDC re-segmentation",
+                                blob_id
+                            )
+                        })?;
+                    data.extend_from_slice(&uncompressed);
+                }
+            }
+
+            resegmented.extend(Self::chunkdict_from_fastcdc(
+                &data,
+                blob_id,
+                image_reference,
+                version,
+                ctx.chunk_size as usize,
+                ctx.digester,
+            ));
+        }
+        Ok(resegmented)
+    }
+
     /// Insert chunks.
     fn insert_chunks(
         ctx: &mut BuildContext,
@@ -583,3 +1407,235 @@ impl Generator {
 }
 
 // Read the blob, get the chunk, fix dump node chunk function, Blob::dump generate a blob
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(blob_id: &str, digest: &str, size: u32) -> ChunkdictChunkInfo {
+        ChunkdictChunkInfo {
+            image_reference: "ref".to_string(),
+            version: "1".to_string(),
+            chunk_blob_id: blob_id.to_string(),
+            chunk_digest: digest.to_string(),
+            chunk_compressed_size: size,
+            chunk_uncompressed_size: size,
+            chunk_compressed_offset: 0,
+            chunk_uncompressed_offset: 0,
+        }
+    }
+
+    #[test]
+    fn fastcdc_covers_input_without_gaps_or_overlap() {
+        let data: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+        let config = FastCdcConfig::new(8192);
+        let mut expected_offset = 0u64;
+        let mut total_len = 0u64;
+        for (offset, len) in FastCdc::new(&data, config) {
+            assert_eq!(offset, expected_offset, "chunks must be contiguous");
+            assert!(len > 0, "chunk length must be non-zero");
+            expected_offset += len;
+            total_len += len;
+        }
+        assert_eq!(total_len, data.len() as u64);
+    }
+
+    #[test]
+    fn fastcdc_boundaries_are_reproducible() {
+        let data: Vec<u8> = (0..100_000u32).map(|i| (i * 7 % 256) as u8).collect();
+        let config = FastCdcConfig::new(4096);
+        let first: Vec<(u64, u64)> = FastCdc::new(&data, config).collect();
+        let second: Vec<(u64, u64)> = FastCdc::new(&data, config).collect();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn chunk_size_stats_empty_is_zeroed() {
+        let stats = ChunkSizeStats::new(&[]);
+        assert_eq!(stats.min, 0);
+        assert_eq!(stats.max, 0);
+        assert_eq!(stats.avg, 0.0);
+    }
+
+    #[test]
+    fn chunk_size_stats_computes_min_max_avg() {
+        let stats = ChunkSizeStats::new(&[10, 20, 30]);
+        assert_eq!(stats.min, 10);
+        assert_eq!(stats.max, 30);
+        assert!((stats.avg - 20.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn dedup_stats_counts_duplicates_per_blob_and_overall() {
+        let chunkdict = vec![
+            chunk("blob-a", "d1", 100),
+            chunk("blob-a", "d1", 100), // duplicate within blob-a
+            chunk("blob-a", "d2", 50),
+            chunk("blob-b", "d1", 100), // same digest as blob-a's d1, but unique within blob-b
+        ];
+        let stats = Generator::compute_dedup_stats(&chunkdict);
+
+        let blob_a = stats.per_blob.iter().find(|b| b.blob_id == "blob-a").unwrap();
+        assert_eq!(blob_a.total_chunks, 3);
+        assert_eq!(blob_a.unique_chunks, 2);
+        assert_eq!(blob_a.duplicate_chunks, 1);
+
+        let blob_b = stats.per_blob.iter().find(|b| b.blob_id == "blob-b").unwrap();
+        assert_eq!(blob_b.total_chunks, 1);
+        assert_eq!(blob_b.unique_chunks, 1);
+
+        // Globally, d1 is seen three times (twice in blob-a, once in blob-b), so only the first occurrence counts
+        // as unique, confirming overall stats use a single dict-wide view rather than summing
+        // each blob's independently-computed unique count.
+        assert_eq!(stats.overall.total_chunks, 4);
+        assert_eq!(stats.overall.unique_chunks, 2);
+        assert_eq!(stats.overall.duplicate_chunks, 2);
+    }
+
+    #[test]
+    fn dedup_stats_match_chunkdict_after_filtering() {
+        // Mirrors what `validate_and_remove_chunks` does once small-blob chunks are retained
+        // out of `chunkdict`: stats computed on the filtered list must not mention the
+        // dropped blob at all, and must reflect only what is left.
+        let mut chunkdict = vec![
+            chunk("tiny-blob", "d1", 10),
+            chunk("big-blob", "d2", 1 << 20),
+            chunk("big-blob", "d2", 1 << 20),
+        ];
+        chunkdict.retain(|c| c.chunk_blob_id != "tiny-blob");
+        let stats = Generator::compute_dedup_stats(&chunkdict);
+
+        assert!(stats.per_blob.iter().all(|b| b.blob_id != "tiny-blob"));
+        assert_eq!(stats.overall.total_chunks, 2);
+        assert_eq!(stats.overall.unique_chunks, 1);
+    }
+
+    #[test]
+    fn merkle_root_is_deterministic_and_depends_on_input() {
+        let algo = nydus_utils::digest::Algorithm::Blake3;
+        let a = RafsDigest::from_buf(b"chunk-a", algo);
+        let b = RafsDigest::from_buf(b"chunk-b", algo);
+
+        let root1 = Generator::merkle_root(&[a, b], algo);
+        let root2 = Generator::merkle_root(&[a, b], algo);
+        assert_eq!(root1, root2);
+
+        let root_single = Generator::merkle_root(&[a], algo);
+        assert_ne!(root1, root_single);
+
+        let root_empty = Generator::merkle_root(&[], algo);
+        assert_eq!(root_empty, RafsDigest::from_buf(&[], algo));
+    }
+
+    #[test]
+    fn chunkdict_dump_round_trips_through_json() {
+        let chunks = vec![chunk("blob-a", "d1", 100)];
+        let blobs = vec![ChunkdictBlobInfo {
+            blob_id: "blob-a".to_string(),
+            blob_compressed_size: 100,
+            blob_uncompressed_size: 100,
+            blob_compressor: "zstd".to_string(),
+            blob_meta_ci_compressed_size: 0,
+            blob_meta_ci_uncompressed_size: 0,
+            blob_meta_ci_offset: 0,
+        }];
+        let dump = ChunkdictDump {
+            chunks: chunks.clone(),
+            blobs: blobs.clone(),
+        };
+
+        let serialized = serde_json::to_string(&dump).unwrap();
+        let restored: ChunkdictDump = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(restored.chunks, chunks);
+        assert_eq!(restored.blobs.len(), blobs.len());
+        assert_eq!(restored.blobs[0].blob_id, blobs[0].blob_id);
+    }
+
+    #[test]
+    fn blob_frame_index_locates_frames_and_slot_padding() {
+        let mut index = BlobFrameIndex::default();
+        index.push(BlobFrameDescriptor {
+            uncompressed_offset: 0,
+            uncompressed_size: 10,
+            compressed_offset: 0,
+            compressed_size: 10,
+            is_hole: false,
+            crc32: 0,
+        });
+        index.push(BlobFrameDescriptor {
+            uncompressed_offset: 4096,
+            uncompressed_size: 20,
+            compressed_offset: 10,
+            compressed_size: 20,
+            is_hole: false,
+            crc32: 0,
+        });
+
+        // Offset 10 falls inside the alignment padding after the first (10-byte) frame, but
+        // before the second frame's slot begins at 4096.
+        let idx = index.locate_index(10).unwrap();
+        assert_eq!(idx, 0);
+        assert_eq!(index.slot_end(idx), 4096);
+
+        let idx = index.locate_index(4096).unwrap();
+        assert_eq!(idx, 1);
+        assert_eq!(index.slot_end(idx), 4096 + 20);
+    }
+
+    #[test]
+    fn blob_node_reader_reads_multi_frame_compressed_blob() {
+        // Highly compressible fixtures, so the compressed frames are meaningfully smaller than
+        // their uncompressed content -- the exact case where `position` (uncompressed space)
+        // and a compressed-space `end` must not be compared directly.
+        let chunk_a = vec![1u8; 5000];
+        let chunk_b = vec![2u8; 3000];
+        let (compressed_a, _) = compress::compress(&chunk_a, compress::Algorithm::Zstd).unwrap();
+        let (compressed_b, _) = compress::compress(&chunk_b, compress::Algorithm::Zstd).unwrap();
+        assert!(
+            compressed_a.len() < chunk_a.len(),
+            "test fixture should actually compress"
+        );
+
+        let aligned_a = nydus_utils::try_round_up_4k(chunk_a.len() as u32).unwrap();
+
+        let mut frame_index = BlobFrameIndex::default();
+        frame_index.push(BlobFrameDescriptor {
+            uncompressed_offset: 0,
+            uncompressed_size: chunk_a.len() as u32,
+            compressed_offset: 0,
+            compressed_size: compressed_a.len() as u32,
+            is_hole: false,
+            crc32: crc32fast::hash(&compressed_a),
+        });
+        frame_index.push(BlobFrameDescriptor {
+            uncompressed_offset: aligned_a as u64,
+            uncompressed_size: chunk_b.len() as u32,
+            compressed_offset: compressed_a.len() as u64,
+            compressed_size: compressed_b.len() as u32,
+            is_hole: false,
+            crc32: crc32fast::hash(&compressed_b),
+        });
+
+        let path = std::env::temp_dir().join(format!(
+            "chunkdict_generator_test_blob_{}_{}",
+            std::process::id(),
+            aligned_a
+        ));
+        std::fs::write(&path, [compressed_a.as_ref(), compressed_b.as_ref()].concat()).unwrap();
+        let file = Arc::new(File::open(&path).unwrap());
+        let file_len = file.metadata().unwrap().len();
+
+        let mut reader =
+            BlobNodeReader::with_frame_index(file, 0, file_len, compress::Algorithm::Zstd, frame_index)
+                .unwrap();
+        let mut out = Vec::new();
+        reader.read_to_end(&mut out).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let mut expected = chunk_a.clone();
+        expected.resize(aligned_a as usize, 0);
+        expected.extend_from_slice(&chunk_b);
+        assert_eq!(out, expected);
+    }
+}